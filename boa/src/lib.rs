@@ -0,0 +1,42 @@
+//! A small, self-contained subset of an ECMAScript parser.
+
+pub mod syntax;
+
+use std::collections::HashMap;
+
+/// An interned string symbol.
+///
+/// Identifiers and string literal values are stored as `Sym`s rather than
+/// owned `String`s so that `Node`s stay cheap to clone and compare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sym(u32);
+
+/// Maps strings to [`Sym`]s and back.
+#[derive(Debug, Default)]
+pub struct Interner {
+    strings: Vec<String>,
+    lookup: HashMap<String, Sym>,
+}
+
+impl Interner {
+    /// Creates a new, empty `Interner`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Interns `s`, returning the existing `Sym` if it was interned before.
+    pub fn get_or_intern(&mut self, s: &str) -> Sym {
+        if let Some(&sym) = self.lookup.get(s) {
+            return sym;
+        }
+        let sym = Sym(self.strings.len() as u32);
+        self.strings.push(s.to_string());
+        self.lookup.insert(s.to_string(), sym);
+        sym
+    }
+
+    /// Resolves a `Sym` back to its string contents.
+    pub fn resolve(&self, sym: Sym) -> &str {
+        &self.strings[sym.0 as usize]
+    }
+}
@@ -0,0 +1,191 @@
+//! Binding targets and destructuring patterns used by declarations.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+
+use crate::{syntax::ast::node::Node, Sym};
+
+/// The target of a single binding: either a plain identifier or a
+/// destructuring `BindingPattern`.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingIdentifier
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindingTarget {
+    /// A plain `BindingIdentifier`, e.g. the `a` in `let a = 1;`.
+    Identifier(Sym),
+    /// A destructuring `BindingPattern`, e.g. the `{ a, b }` in `let { a, b } = o;`.
+    Pattern(BindingPattern),
+}
+
+/// A destructuring binding pattern appearing on the left-hand side of a
+/// variable declaration.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+#[derive(Debug, Clone, PartialEq)]
+pub enum BindingPattern {
+    /// `{ a, b: c, d = 1, ...rest }`
+    Object(Vec<ObjectPatternElement>),
+    /// `[x, , y = 2, ...rest]`. Elisions (empty slots) are represented as `None`.
+    Array(Vec<Option<ArrayPatternElement>>),
+}
+
+/// A single element of an object binding pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectPatternElement {
+    /// `a` or `a = 1`.
+    SingleName {
+        ident: Sym,
+        default_init: Option<Node>,
+    },
+    /// `b: c`, `[expr]: c`, or either with a default.
+    Property {
+        key: PropertyKey,
+        target: BindingTarget,
+        default_init: Option<Node>,
+    },
+    /// `...rest`. Must be the last element and cannot have a default.
+    RestProperty(Sym),
+}
+
+/// The key of an object binding pattern property.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyKey {
+    /// `b` in `{ b: c }`.
+    Literal(Sym),
+    /// `expr` in `{ [expr]: c }`.
+    Computed(Node),
+}
+
+/// A single element of an array binding pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayPatternElement {
+    /// `x` or `x = 1`.
+    SingleName {
+        target: BindingTarget,
+        default_init: Option<Node>,
+    },
+    /// `...rest`. Must be the last element and cannot have a default.
+    Rest(BindingTarget),
+}
+
+/// Compares two optional `Node`s, ignoring span; see
+/// `Node::eq_ignoring_span`.
+fn option_eq_ignoring_span(a: Option<&Node>, b: Option<&Node>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.eq_ignoring_span(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl BindingTarget {
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to a nested `Node` (a computed property key or a
+    /// default initializer).
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Identifier(a), Self::Identifier(b)) => a == b,
+            (Self::Pattern(a), Self::Pattern(b)) => a.eq_ignoring_span(b),
+            _ => false,
+        }
+    }
+}
+
+impl BindingPattern {
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to a nested `Node`.
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Object(a), Self::Object(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq_ignoring_span(b))
+            }
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len()
+                    && a.iter().zip(b).all(|(a, b)| match (a, b) {
+                        (Some(a), Some(b)) => a.eq_ignoring_span(b),
+                        (None, None) => true,
+                        _ => false,
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ObjectPatternElement {
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to a nested `Node`.
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::SingleName {
+                    ident: ident_a,
+                    default_init: init_a,
+                },
+                Self::SingleName {
+                    ident: ident_b,
+                    default_init: init_b,
+                },
+            ) => ident_a == ident_b && option_eq_ignoring_span(init_a.as_ref(), init_b.as_ref()),
+            (
+                Self::Property {
+                    key: key_a,
+                    target: target_a,
+                    default_init: init_a,
+                },
+                Self::Property {
+                    key: key_b,
+                    target: target_b,
+                    default_init: init_b,
+                },
+            ) => {
+                key_a.eq_ignoring_span(key_b)
+                    && target_a.eq_ignoring_span(target_b)
+                    && option_eq_ignoring_span(init_a.as_ref(), init_b.as_ref())
+            }
+            (Self::RestProperty(a), Self::RestProperty(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl PropertyKey {
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to a nested `Node`.
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Literal(a), Self::Literal(b)) => a == b,
+            (Self::Computed(a), Self::Computed(b)) => a.eq_ignoring_span(b),
+            _ => false,
+        }
+    }
+}
+
+impl ArrayPatternElement {
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to a nested `Node`.
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::SingleName {
+                    target: target_a,
+                    default_init: init_a,
+                },
+                Self::SingleName {
+                    target: target_b,
+                    default_init: init_b,
+                },
+            ) => target_a.eq_ignoring_span(target_b) && option_eq_ignoring_span(init_a.as_ref(), init_b.as_ref()),
+            (Self::Rest(a), Self::Rest(b)) => a.eq_ignoring_span(b),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Reserved words.
+
+/// An ECMAScript reserved word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keyword {
+    Var,
+    Let,
+    Const,
+    Delete,
+    Void,
+    TypeOf,
+}
+
+impl Keyword {
+    /// Looks up the keyword matching `s`, if any.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Option<Self> {
+        Some(match s {
+            "var" => Self::Var,
+            "let" => Self::Let,
+            "const" => Self::Const,
+            "delete" => Self::Delete,
+            "void" => Self::Void,
+            "typeof" => Self::TypeOf,
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for Keyword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Var => "var",
+            Self::Let => "let",
+            Self::Const => "const",
+            Self::Delete => "delete",
+            Self::Void => "void",
+            Self::TypeOf => "typeof",
+        };
+        f.write_str(s)
+    }
+}
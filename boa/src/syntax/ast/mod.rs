@@ -0,0 +1,9 @@
+pub mod declaration;
+pub mod keyword;
+pub mod node;
+pub mod op;
+pub mod position;
+pub mod punc;
+pub mod span;
+pub mod string_literal;
+pub mod token;
@@ -0,0 +1,226 @@
+//! The `Node` AST type.
+
+use crate::{
+    syntax::ast::{
+        declaration::BindingTarget, op::BinOp, op::UnaryOp, span::Span,
+        string_literal::StringLiteral,
+    },
+    Sym,
+};
+
+/// A parsed AST node, together with the [`Span`] of source text it came from.
+///
+/// Every `Node` is built through one of the constructors below, each of which
+/// takes (or derives, by merging its children's spans) a `Span` — so there is
+/// no path to a `Node` that skips attaching one.
+#[derive(Debug, Clone)]
+pub struct Node {
+    kind: NodeKind,
+    span: Span,
+}
+
+/// The shape of a [`Node`], without its span.
+///
+/// `NodeKind`'s derived `PartialEq` recurses into nested `Node`s via their own
+/// `PartialEq` impl (below), which *does* compare `span` — so comparing two
+/// `NodeKind`s directly is span-sensitive at every depth. For a span-agnostic
+/// comparison, use [`Node::eq_ignoring_span`], which walks each variant by
+/// hand instead of going through derived equality.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    /// A bare identifier reference.
+    Identifier(Sym),
+    /// A string literal.
+    StringLiteral(StringLiteral),
+    /// A numeric literal.
+    NumericLiteral(f64),
+    /// `var`/`let`/`const` declarations.
+    VarDecl(Vec<(BindingTarget, Option<Node>)>),
+    /// A binary operation, e.g. `a ** b`.
+    BinOp {
+        op: BinOp,
+        lhs: Box<Node>,
+        rhs: Box<Node>,
+    },
+    /// A unary operation, e.g. `typeof a`.
+    UnaryOp { op: UnaryOp, target: Box<Node> },
+    /// A placeholder inserted by error-recovery parsing in place of a node
+    /// that failed to parse, so the surrounding structure (a declaration
+    /// list, a statement list, ...) stays shaped correctly.
+    Error,
+    /// A list of statements, e.g. a whole program or a block body.
+    ///
+    /// `strict` records whether this list's *directive prologue* — the
+    /// leading run of plain string-literal expression statements — contains
+    /// an unescaped `"use strict"`/`'use strict'` directive. An escaped
+    /// directive (e.g. `"use\ strict"`) cooks to the same string but
+    /// must not enable strict mode, which is why this is computed from the
+    /// directive's raw text rather than just checking for a string literal.
+    StatementList { statements: Vec<Node>, strict: bool },
+}
+
+impl Node {
+    /// Builds an identifier reference node.
+    pub fn identifier(sym: Sym, span: Span) -> Self {
+        Self {
+            kind: NodeKind::Identifier(sym),
+            span,
+        }
+    }
+
+    /// Builds a string literal node.
+    pub fn string_literal(lit: StringLiteral, span: Span) -> Self {
+        Self {
+            kind: NodeKind::StringLiteral(lit),
+            span,
+        }
+    }
+
+    /// Builds a numeric literal node.
+    pub fn numeric_literal(value: f64, span: Span) -> Self {
+        Self {
+            kind: NodeKind::NumericLiteral(value),
+            span,
+        }
+    }
+
+    /// Builds a `var`/`let`/`const` declaration list node.
+    pub fn var_decl(list: Vec<(BindingTarget, Option<Node>)>, span: Span) -> Self {
+        Self {
+            kind: NodeKind::VarDecl(list),
+            span,
+        }
+    }
+
+    /// Builds a binary operation node. The span is the merge of `lhs`'s and
+    /// `rhs`'s spans, since a binary operation always covers exactly both of
+    /// its operands.
+    pub fn bin_op(op: BinOp, lhs: Node, rhs: Node) -> Self {
+        let span = lhs.span.merge(rhs.span);
+        Self {
+            kind: NodeKind::BinOp {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            },
+            span,
+        }
+    }
+
+    /// Builds a unary operation node.
+    pub fn unary_op(op: UnaryOp, target: Node, span: Span) -> Self {
+        Self {
+            kind: NodeKind::UnaryOp {
+                op,
+                target: Box::new(target),
+            },
+            span,
+        }
+    }
+
+    /// Builds an error placeholder node, inserted by error-recovery parsing
+    /// in place of a node that failed to parse.
+    pub fn error(span: Span) -> Self {
+        Self {
+            kind: NodeKind::Error,
+            span,
+        }
+    }
+
+    /// Builds a statement list node. `strict` records whether the list's
+    /// directive prologue enables strict mode; see [`NodeKind::StatementList`].
+    pub fn statement_list(statements: Vec<Node>, strict: bool, span: Span) -> Self {
+        Self {
+            kind: NodeKind::StatementList { statements, strict },
+            span,
+        }
+    }
+
+    /// The kind of this node.
+    pub fn kind(&self) -> &NodeKind {
+        &self.kind
+    }
+
+    /// The span of source text this node covers.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns `true` if `self` and `other` have the same shape, ignoring any
+    /// `Span` attached to them or their descendants.
+    ///
+    /// Unlike `self == other` (which, via `NodeKind`'s derived `PartialEq`, is
+    /// span-sensitive all the way down), this walks each variant by hand and
+    /// recurses with `eq_ignoring_span` instead of `==`, so a nested `Node`'s
+    /// span never affects the result either.
+    pub fn eq_ignoring_span(&self, other: &Self) -> bool {
+        match (&self.kind, &other.kind) {
+            (NodeKind::Identifier(a), NodeKind::Identifier(b)) => a == b,
+            (NodeKind::StringLiteral(a), NodeKind::StringLiteral(b)) => a == b,
+            (NodeKind::NumericLiteral(a), NodeKind::NumericLiteral(b)) => a == b,
+            (NodeKind::VarDecl(a), NodeKind::VarDecl(b)) => {
+                a.len() == b.len()
+                    && a.iter().zip(b).all(|((ta, ia), (tb, ib))| {
+                        ta.eq_ignoring_span(tb) && option_eq_ignoring_span(ia.as_ref(), ib.as_ref())
+                    })
+            }
+            (
+                NodeKind::BinOp {
+                    op: op_a,
+                    lhs: lhs_a,
+                    rhs: rhs_a,
+                },
+                NodeKind::BinOp {
+                    op: op_b,
+                    lhs: lhs_b,
+                    rhs: rhs_b,
+                },
+            ) => op_a == op_b && lhs_a.eq_ignoring_span(lhs_b) && rhs_a.eq_ignoring_span(rhs_b),
+            (
+                NodeKind::UnaryOp {
+                    op: op_a,
+                    target: target_a,
+                },
+                NodeKind::UnaryOp {
+                    op: op_b,
+                    target: target_b,
+                },
+            ) => op_a == op_b && target_a.eq_ignoring_span(target_b),
+            (NodeKind::Error, NodeKind::Error) => true,
+            (
+                NodeKind::StatementList {
+                    statements: stmts_a,
+                    strict: strict_a,
+                },
+                NodeKind::StatementList {
+                    statements: stmts_b,
+                    strict: strict_b,
+                },
+            ) => {
+                strict_a == strict_b
+                    && stmts_a.len() == stmts_b.len()
+                    && stmts_a
+                        .iter()
+                        .zip(stmts_b)
+                        .all(|(a, b)| a.eq_ignoring_span(b))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Compares two optional `Node`s, ignoring span, treating `None`/`None` as
+/// equal and any `None`/`Some` mismatch as unequal.
+fn option_eq_ignoring_span(a: Option<&Node>, b: Option<&Node>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.eq_ignoring_span(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind && self.span == other.span
+    }
+}
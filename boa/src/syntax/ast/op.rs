@@ -0,0 +1,25 @@
+//! Operators.
+
+/// A numeric binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumOp {
+    Exp,
+}
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Num(NumOp),
+}
+
+/// A unary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Delete,
+    Void,
+    TypeOf,
+    Plus,
+    Minus,
+    Not,
+    Neg,
+}
@@ -0,0 +1,37 @@
+//! Source text positions.
+
+/// A 1-indexed line/column position in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    line: u32,
+    column: u32,
+}
+
+impl Position {
+    /// Creates a new `Position`.
+    pub fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+
+    /// The 1-indexed line number.
+    pub fn line(self) -> u32 {
+        self.line
+    }
+
+    /// The 1-indexed column number.
+    pub fn column(self) -> u32 {
+        self.column
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
@@ -0,0 +1,46 @@
+//! Punctuators.
+
+/// An ECMAScript punctuator token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punctuator {
+    Add,
+    Sub,
+    Not,
+    Neg,
+    Exp,
+    Assign,
+    Comma,
+    Semicolon,
+    Colon,
+    Spread,
+    OpenBlock,
+    CloseBlock,
+    OpenBracket,
+    CloseBracket,
+    OpenParen,
+    CloseParen,
+}
+
+impl std::fmt::Display for Punctuator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Not => "!",
+            Self::Neg => "~",
+            Self::Exp => "**",
+            Self::Assign => "=",
+            Self::Comma => ",",
+            Self::Semicolon => ";",
+            Self::Colon => ":",
+            Self::Spread => "...",
+            Self::OpenBlock => "{",
+            Self::CloseBlock => "}",
+            Self::OpenBracket => "[",
+            Self::CloseBracket => "]",
+            Self::OpenParen => "(",
+            Self::CloseParen => ")",
+        };
+        f.write_str(s)
+    }
+}
@@ -0,0 +1,80 @@
+//! Source span tracking for AST nodes.
+//!
+//! Every `Node` carries the `Span` of the source text it was parsed from, so
+//! diagnostics and tooling can point at a precise range instead of a single
+//! position. `Cursor` already tracks a [`Position`] per token, so parsers
+//! build a `Span` by merging the positions/spans of what they consumed.
+//!
+//! Tokens record only the position they start at, so spans are built from
+//! token start positions alone: a node parsed from a single token (an
+//! identifier or a literal) gets a zero-width span at that token's start, a
+//! unary or binary operation's span ends where its last operand's span ends
+//! (the start of its last token), and a declaration list's or statement
+//! list's span ends at the start of the token that follows it.
+
+use crate::syntax::ast::position::Position;
+
+/// A contiguous range in the source text, delimited by a start position and
+/// an end position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    start: Position,
+    end: Position,
+}
+
+impl Span {
+    /// Creates a new `Span` from a start and end position.
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the position of the first byte covered by this span.
+    pub fn start(self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position of this span (a token start position; see
+    /// the module docs for which token).
+    pub fn end(self) -> Position {
+        self.end
+    }
+
+    /// Merges two spans into one that covers both, assuming `self` starts no
+    /// later than `other`.
+    pub fn merge(self, other: Self) -> Self {
+        Self::new(self.start, other.end)
+    }
+}
+
+impl From<Position> for Span {
+    /// A zero-width span at `pos`, for a node parsed from a single token or
+    /// an error placeholder.
+    fn from(pos: Position) -> Self {
+        Self::new(pos, pos)
+    }
+}
+
+/// Asserts that two `Node` trees are structurally equal, ignoring any `Span`
+/// attached to them or their descendants.
+///
+/// Parser tests build a lot of golden ASTs by hand; without this, adding
+/// spans would make every one of those assertions churn whenever a position
+/// shifted by a single column. Mirrors the `assert_eq_ignore_span!` helper
+/// swc uses for the same reason. Relies on `Node::eq_ignoring_span`, which
+/// recurses through each `NodeKind` variant by hand (rather than through
+/// `Node`'s derived-feeling `PartialEq`, which *is* span-sensitive — see its
+/// impl) so a span mismatch anywhere in the tree, not just at the root,
+/// doesn't fail the comparison.
+#[macro_export]
+macro_rules! assert_eq_ignore_span {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => assert!(
+                $crate::syntax::ast::node::Node::eq_ignoring_span(left, right),
+                "AST mismatch (ignoring spans):\n  left:  {:?}\n  right: {:?}",
+                left,
+                right,
+            ),
+        }
+    };
+}
@@ -0,0 +1,67 @@
+//! String literal representation, including escape tracking.
+//!
+//! Mirrors the `Str { value, has_escape }` representation swc's lexer uses:
+//! the lexer hands the parser both the cooked `value` and whether the source
+//! text contained any escape sequence, so callers that care about the raw
+//! source (directive prologues, codegen round-tripping the original quoting)
+//! don't have to re-derive it from a normalized `String`.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-literals-string-literals
+
+use crate::{Interner, Sym};
+
+/// A parsed string literal, along with enough information about its source
+/// text to answer "was this written with an escape sequence?" without
+/// re-lexing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StringLiteral {
+    /// The cooked value of the literal, after escape processing.
+    value: Sym,
+    /// The literal's raw source text, including the surrounding quotes and
+    /// any escape sequences exactly as written.
+    raw: Sym,
+    /// `true` if the literal's source text contains an escape sequence
+    /// (a `\n`-style escape, a unicode escape, a line continuation, ...).
+    has_escape: bool,
+}
+
+impl StringLiteral {
+    /// Creates a new `StringLiteral`.
+    pub fn new(value: Sym, raw: Sym, has_escape: bool) -> Self {
+        Self {
+            value,
+            raw,
+            has_escape,
+        }
+    }
+
+    /// The cooked value of the literal.
+    pub fn value(self) -> Sym {
+        self.value
+    }
+
+    /// The literal's raw source text, including the surrounding quotes.
+    pub fn raw(self) -> Sym {
+        self.raw
+    }
+
+    /// `true` if the literal's source text contains an escape sequence.
+    pub fn has_escape(self) -> bool {
+        self.has_escape
+    }
+
+    /// Returns `true` if this literal's *source text* is exactly `"use strict"`
+    /// or `'use strict'`, i.e. a valid `"use strict"` directive
+    /// (https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive).
+    ///
+    /// This must check the raw source slice, not the cooked `value`: an
+    /// escaped equivalent such as `"use\ strict"` cooks to the same
+    /// string but is not a valid directive and must not enable strict mode.
+    pub fn is_use_strict_directive(self, interner: &Interner) -> bool {
+        !self.has_escape
+            && matches!(interner.resolve(self.raw), "\"use strict\"" | "'use strict'")
+    }
+}
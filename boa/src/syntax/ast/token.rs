@@ -0,0 +1,67 @@
+//! Lexer tokens.
+
+use crate::{
+    syntax::ast::{keyword::Keyword, position::Position, punc::Punctuator, string_literal::StringLiteral},
+    Interner, Sym,
+};
+
+/// The kind of a lexed token.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Identifier(Sym),
+    Keyword(Keyword),
+    Punctuator(Punctuator),
+    StringLiteral(StringLiteral),
+    NumericLiteral(f64),
+    EOF,
+}
+
+impl From<Keyword> for TokenKind {
+    fn from(kw: Keyword) -> Self {
+        Self::Keyword(kw)
+    }
+}
+
+impl From<Punctuator> for TokenKind {
+    fn from(p: Punctuator) -> Self {
+        Self::Punctuator(p)
+    }
+}
+
+/// A single lexed token, together with the position it starts at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub pos: Position,
+}
+
+impl Token {
+    /// Creates a new `Token`.
+    pub fn new(kind: TokenKind, pos: Position) -> Self {
+        Self { kind, pos }
+    }
+
+    /// A human-readable rendering of this token, for error messages.
+    pub fn display<'a>(&'a self, interner: &'a Interner) -> impl std::fmt::Display + 'a {
+        struct Display<'a> {
+            token: &'a Token,
+            interner: &'a Interner,
+        }
+        impl std::fmt::Display for Display<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.token.kind {
+                    TokenKind::Identifier(sym) => f.write_str(self.interner.resolve(*sym)),
+                    TokenKind::Keyword(kw) => write!(f, "{kw}"),
+                    TokenKind::Punctuator(p) => write!(f, "{p}"),
+                    TokenKind::StringLiteral(lit) => f.write_str(self.interner.resolve(lit.value())),
+                    TokenKind::NumericLiteral(n) => write!(f, "{n}"),
+                    TokenKind::EOF => f.write_str("end of file"),
+                }
+            }
+        }
+        Display {
+            token: self,
+            interner,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Lexer errors.
+
+use crate::syntax::ast::position::Position;
+
+/// An error encountered while lexing.
+#[derive(Debug, Clone)]
+pub enum LexError {
+    UnexpectedChar(char, Position),
+    UnterminatedString(Position),
+    /// The source bytes given to the parser aren't valid UTF-8, so they can't
+    /// be lexed at all.
+    InvalidUtf8,
+}
+
+impl std::fmt::Display for LexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedChar(c, pos) => write!(f, "unexpected character '{c}' at {pos}"),
+            Self::UnterminatedString(pos) => write!(f, "unterminated string literal at {pos}"),
+            Self::InvalidUtf8 => f.write_str("source is not valid UTF-8"),
+        }
+    }
+}
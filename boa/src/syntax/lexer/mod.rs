@@ -0,0 +1,249 @@
+//! A minimal lexer covering the punctuators, keywords, identifiers, string
+//! literals and numeric literals this parser subset understands.
+
+mod error;
+
+pub use error::LexError;
+
+use crate::{
+    syntax::ast::{
+        keyword::Keyword, position::Position, punc::Punctuator, string_literal::StringLiteral,
+        token::{Token, TokenKind},
+    },
+    Interner,
+};
+use std::{iter::Peekable, str::Chars};
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: u32,
+    column: u32,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            chars: source.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn pos(&self) -> Position {
+        Position::new(self.line, self.column)
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            match self.peek() {
+                Some(c) if c.is_whitespace() => {
+                    self.advance();
+                }
+                Some('/') => {
+                    let mut lookahead = self.chars.clone();
+                    lookahead.next();
+                    match lookahead.peek() {
+                        Some('/') => {
+                            while !matches!(self.peek(), Some('\n') | None) {
+                                self.advance();
+                            }
+                        }
+                        Some('*') => {
+                            self.advance();
+                            self.advance();
+                            loop {
+                                match self.advance() {
+                                    None => break,
+                                    Some('*') if self.peek() == Some('/') => {
+                                        self.advance();
+                                        break;
+                                    }
+                                    _ => {}
+                                }
+                            }
+                        }
+                        _ => break,
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn read_string(&mut self, interner: &mut Interner) -> Result<StringLiteral, LexError> {
+        let quote = self.advance().expect("caller already peeked the quote");
+        let start = self.pos();
+        let mut raw = String::new();
+        raw.push(quote);
+        let mut cooked = String::new();
+        let mut has_escape = false;
+
+        loop {
+            match self.advance() {
+                None => return Err(LexError::UnterminatedString(start)),
+                Some(c) if c == quote => {
+                    raw.push(c);
+                    break;
+                }
+                Some('\\') => {
+                    has_escape = true;
+                    raw.push('\\');
+                    match self.advance() {
+                        None => return Err(LexError::UnterminatedString(start)),
+                        Some('u') => {
+                            raw.push('u');
+                            let mut hex = String::with_capacity(4);
+                            for _ in 0..4 {
+                                let digit = self.advance().ok_or(LexError::UnterminatedString(start))?;
+                                raw.push(digit);
+                                hex.push(digit);
+                            }
+                            let code = u32::from_str_radix(&hex, 16)
+                                .map_err(|_| LexError::UnexpectedChar('u', start))?;
+                            let c = char::from_u32(code).ok_or(LexError::UnexpectedChar('u', start))?;
+                            cooked.push(c);
+                        }
+                        Some(e) => {
+                            raw.push(e);
+                            cooked.push(match e {
+                                'n' => '\n',
+                                't' => '\t',
+                                'r' => '\r',
+                                '0' => '\0',
+                                other => other,
+                            });
+                        }
+                    }
+                }
+                Some(c) => {
+                    raw.push(c);
+                    cooked.push(c);
+                }
+            }
+        }
+
+        Ok(StringLiteral::new(
+            interner.get_or_intern(&cooked),
+            interner.get_or_intern(&raw),
+            has_escape,
+        ))
+    }
+
+    fn read_identifier_like(&mut self) -> String {
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_alphanumeric() || c == '_' || c == '$' {
+                s.push(c);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        s
+    }
+
+    fn read_number(&mut self) -> f64 {
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                s.push(c);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+        s.parse().unwrap_or(0.0)
+    }
+
+    fn next_token(&mut self, interner: &mut Interner) -> Result<Option<Token>, LexError> {
+        self.skip_trivia();
+        let pos = self.pos();
+        let Some(c) = self.peek() else {
+            return Ok(None);
+        };
+
+        let kind = match c {
+            '"' | '\'' => TokenKind::StringLiteral(self.read_string(interner)?),
+            c if c.is_ascii_digit() => TokenKind::NumericLiteral(self.read_number()),
+            c if c.is_alphabetic() || c == '_' || c == '$' => {
+                let word = self.read_identifier_like();
+                match Keyword::from_str(&word) {
+                    Some(kw) => TokenKind::Keyword(kw),
+                    None => TokenKind::Identifier(interner.get_or_intern(&word)),
+                }
+            }
+            '*' => {
+                self.advance();
+                if self.peek() == Some('*') {
+                    self.advance();
+                    TokenKind::Punctuator(Punctuator::Exp)
+                } else {
+                    return Err(LexError::UnexpectedChar('*', pos));
+                }
+            }
+            '.' => {
+                self.advance();
+                if self.peek() == Some('.') {
+                    self.advance();
+                    if self.peek() == Some('.') {
+                        self.advance();
+                        TokenKind::Punctuator(Punctuator::Spread)
+                    } else {
+                        return Err(LexError::UnexpectedChar('.', pos));
+                    }
+                } else {
+                    return Err(LexError::UnexpectedChar('.', pos));
+                }
+            }
+            _ => {
+                self.advance();
+                TokenKind::Punctuator(match c {
+                    '+' => Punctuator::Add,
+                    '-' => Punctuator::Sub,
+                    '!' => Punctuator::Not,
+                    '~' => Punctuator::Neg,
+                    '=' => Punctuator::Assign,
+                    ',' => Punctuator::Comma,
+                    ';' => Punctuator::Semicolon,
+                    ':' => Punctuator::Colon,
+                    '{' => Punctuator::OpenBlock,
+                    '}' => Punctuator::CloseBlock,
+                    '[' => Punctuator::OpenBracket,
+                    ']' => Punctuator::CloseBracket,
+                    '(' => Punctuator::OpenParen,
+                    ')' => Punctuator::CloseParen,
+                    other => return Err(LexError::UnexpectedChar(other, pos)),
+                })
+            }
+        };
+
+        Ok(Some(Token::new(kind, pos)))
+    }
+}
+
+/// Lexes `source` in full, returning every token (including a trailing
+/// `TokenKind::EOF`).
+pub fn tokenize(source: &str, interner: &mut Interner) -> Result<Vec<Token>, LexError> {
+    let mut lexer = Lexer::new(source);
+    let mut tokens = Vec::new();
+    while let Some(tok) = lexer.next_token(interner)? {
+        tokens.push(tok);
+    }
+    tokens.push(Token::new(TokenKind::EOF, lexer.pos()));
+    Ok(tokens)
+}
@@ -0,0 +1,200 @@
+//! The token cursor parsers drive themselves with.
+
+use crate::{
+    syntax::ast::{position::Position, punc::Punctuator, token::{Token, TokenKind}},
+    Interner,
+};
+
+use super::ParseError;
+
+/// A cursor over a pre-lexed token stream.
+///
+/// By default a `Cursor` runs in strict mode: [`Cursor::emit_error`] re-raises
+/// whatever it's given immediately, so a single bad token aborts the whole
+/// parse, matching the crate's original behavior. [`Cursor::new_recovering`]
+/// instead makes `emit_error` record the diagnostic into a sink and return
+/// `Ok(())`, so callers can resynchronize and keep parsing; the accumulated
+/// errors are read back out with [`Cursor::take_errors`].
+pub struct Cursor<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    recovering: bool,
+    errors: Vec<ParseError>,
+    _interner_lifetime: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a new strict `Cursor` over `tokens`.
+    pub fn new(tokens: Vec<Token>) -> Self {
+        Self {
+            tokens,
+            pos: 0,
+            recovering: false,
+            errors: Vec::new(),
+            _interner_lifetime: std::marker::PhantomData,
+        }
+    }
+
+    /// Creates a new `Cursor` in error-recovery mode: see [`Cursor::emit_error`].
+    pub fn new_recovering(tokens: Vec<Token>) -> Self {
+        Self {
+            recovering: true,
+            ..Self::new(tokens)
+        }
+    }
+
+    /// Returns the token at `offset` tokens ahead of the cursor, if any.
+    pub fn peek(&self, offset: usize) -> Option<Token> {
+        self.tokens.get(self.pos + offset).cloned()
+    }
+
+    /// Consumes and returns the next token, if any.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Token> {
+        let tok = self.tokens.get(self.pos).cloned();
+        if tok.is_some() {
+            self.pos += 1;
+        }
+        tok
+    }
+
+    /// Un-consumes the last token returned by [`Cursor::next`].
+    pub fn back(&mut self) {
+        debug_assert!(self.pos > 0, "back() called with nothing to back up over");
+        self.pos -= 1;
+    }
+
+    /// Expects the next token to match `kind`, consuming it; otherwise
+    /// returns a `ParseError::Expected`.
+    pub fn expect<K>(
+        &mut self,
+        kind: K,
+        context: &'static str,
+        interner: &Interner,
+    ) -> Result<(), ParseError>
+    where
+        K: Into<TokenKind>,
+    {
+        let kind = kind.into();
+        match self.next() {
+            Some(tok) if tok.kind == kind => Ok(()),
+            Some(tok) => Err(ParseError::expected(
+                vec![format!("{kind:?}")],
+                tok.display(interner).to_string(),
+                tok.pos,
+                context,
+            )),
+            None => Err(ParseError::AbruptEnd),
+        }
+    }
+
+    /// Looks for a statement-terminating `;`, consuming it if present.
+    /// Returns `(true, _)` if the statement is terminated (a `;` was
+    /// consumed, ASI applies at `}`/EOF, or `strict` is `false` and the next
+    /// token is anything other than a `,`), or `(false, Some(tok))` with the
+    /// unexpected next token otherwise. The lexer emits no line terminators,
+    /// so a non-strict caller accepts a missing `;` before any token but `,`.
+    pub fn peek_semicolon(&mut self, strict: bool) -> (bool, Option<Token>) {
+        match self.peek(0) {
+            Some(tok) if tok.kind == TokenKind::Punctuator(Punctuator::Semicolon) => {
+                self.next();
+                (true, None)
+            }
+            Some(tok)
+                if matches!(
+                    tok.kind,
+                    TokenKind::Punctuator(Punctuator::CloseBlock) | TokenKind::EOF
+                ) =>
+            {
+                (true, Some(tok))
+            }
+            None => (true, None),
+            Some(tok) if !strict && tok.kind != TokenKind::Punctuator(Punctuator::Comma) => {
+                (true, Some(tok))
+            }
+            Some(tok) => (false, Some(tok)),
+        }
+    }
+
+    /// Expects a statement-terminating `;`, consuming it if present and
+    /// erroring if neither a `;` nor ASI applies.
+    pub fn expect_semicolon(
+        &mut self,
+        strict: bool,
+        context: &'static str,
+        interner: &Interner,
+    ) -> Result<(), ParseError> {
+        match self.peek_semicolon(strict) {
+            (true, _) => Ok(()),
+            (false, Some(tok)) => Err(ParseError::expected(
+                vec![Punctuator::Semicolon.to_string()],
+                tok.display(interner).to_string(),
+                tok.pos,
+                context,
+            )),
+            (false, None) => Err(ParseError::AbruptEnd),
+        }
+    }
+
+    /// Records `e` as a diagnostic and keeps going, if this cursor is in
+    /// recovery mode; otherwise re-raises it immediately. This is the single
+    /// choke point that makes "treat every emitted error as fatal" (the
+    /// strict/default mode) and "collect and keep parsing" (recovery mode)
+    /// share one code path.
+    pub fn emit_error(&mut self, e: ParseError) -> Result<(), ParseError> {
+        if self.recovering {
+            self.errors.push(e);
+            Ok(())
+        } else {
+            Err(e)
+        }
+    }
+
+    /// Skips tokens until a synchronization point — a `;`, a `}`, or EOF —
+    /// so the next parser up the stack can resume from a known-good spot.
+    /// Always consumes at least one token, so a malformed token that is
+    /// itself a sync point can't make this loop forever without progress.
+    pub fn recover_to_sync_point(&mut self) {
+        match self.next() {
+            None => return,
+            Some(tok)
+                if matches!(
+                    tok.kind,
+                    TokenKind::Punctuator(Punctuator::Semicolon)
+                        | TokenKind::Punctuator(Punctuator::CloseBlock)
+                ) =>
+            {
+                return
+            }
+            Some(_) => {}
+        }
+
+        loop {
+            match self.peek(0) {
+                None => break,
+                Some(tok) if tok.kind == TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    self.next();
+                    break;
+                }
+                Some(tok) if tok.kind == TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                Some(_) => {
+                    self.next();
+                }
+            }
+        }
+    }
+
+    /// The position of the next token, or of EOF if there isn't one.
+    pub fn pos(&self) -> Position {
+        self.peek(0).map_or_else(
+            || self.tokens.last().map_or_else(Position::default, |t| t.pos),
+            |t| t.pos,
+        )
+    }
+
+    /// Drains and returns every error recorded via [`Cursor::emit_error`]
+    /// while in recovery mode.
+    pub fn take_errors(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.errors)
+    }
+}
@@ -0,0 +1,76 @@
+//! Parser errors.
+
+use crate::{syntax::ast::position::Position, syntax::lexer::LexError};
+
+/// An error encountered while parsing.
+#[derive(Debug, Clone)]
+pub enum ParseError {
+    /// The token stream ended where a token was still expected.
+    AbruptEnd,
+    /// A specific set of tokens was expected, but something else was found.
+    Expected {
+        expected: Vec<String>,
+        found: String,
+        pos: Position,
+        context: &'static str,
+    },
+    /// A parse error that doesn't fit the "expected X, found Y" shape, e.g.
+    /// an early-error check like the `**` unary-base restriction.
+    General {
+        message: &'static str,
+        pos: Position,
+    },
+    /// An error produced by the lexer.
+    Lex(LexError),
+}
+
+impl ParseError {
+    /// Creates an `Expected` error.
+    pub fn expected(
+        expected: Vec<String>,
+        found: String,
+        pos: Position,
+        context: &'static str,
+    ) -> Self {
+        Self::Expected {
+            expected,
+            found,
+            pos,
+            context,
+        }
+    }
+
+    /// Creates a `General` error.
+    pub fn general(message: &'static str, pos: Position) -> Self {
+        Self::General { message, pos }
+    }
+}
+
+impl From<LexError> for ParseError {
+    fn from(e: LexError) -> Self {
+        Self::Lex(e)
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AbruptEnd => f.write_str("unexpected end of input"),
+            Self::Expected {
+                expected,
+                found,
+                pos,
+                context,
+            } => write!(
+                f,
+                "expected {} in {}, found '{}' at {}",
+                expected.join(", "),
+                context,
+                found,
+                pos
+            ),
+            Self::General { message, pos } => write!(f, "{message} at {pos}"),
+            Self::Lex(e) => write!(f, "{e}"),
+        }
+    }
+}
@@ -18,7 +18,7 @@ use crate::{
         },
         parser::{
             expression::{unary::UnaryExpression, update::UpdateExpression},
-            AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
     Interner,
@@ -56,16 +56,16 @@ impl ExponentiationExpression {
     /// Checks by looking at the next token to see whether it's a unary operator or not.
     fn is_unary_expression(cursor: &mut Cursor<'_>) -> bool {
         if let Some(tok) = cursor.peek(0) {
-            match tok.kind {
+            matches!(
+                tok.kind,
                 TokenKind::Keyword(Keyword::Delete)
-                | TokenKind::Keyword(Keyword::Void)
-                | TokenKind::Keyword(Keyword::TypeOf)
-                | TokenKind::Punctuator(Punctuator::Add)
-                | TokenKind::Punctuator(Punctuator::Sub)
-                | TokenKind::Punctuator(Punctuator::Not)
-                | TokenKind::Punctuator(Punctuator::Neg) => true,
-                _ => false,
-            }
+                    | TokenKind::Keyword(Keyword::Void)
+                    | TokenKind::Keyword(Keyword::TypeOf)
+                    | TokenKind::Punctuator(Punctuator::Add)
+                    | TokenKind::Punctuator(Punctuator::Sub)
+                    | TokenKind::Punctuator(Punctuator::Not)
+                    | TokenKind::Punctuator(Punctuator::Neg)
+            )
         } else {
             false
         }
@@ -77,19 +77,35 @@ impl TokenParser for ExponentiationExpression {
 
     fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
         if Self::is_unary_expression(cursor) {
-            return UnaryExpression::new(self.allow_yield, self.allow_await)
-                .parse(cursor, interner);
+            let unary =
+                UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+
+            // The base of `**` may not be an unparenthesized `UnaryExpression`
+            // (https://tc39.es/ecma262/#sec-exp-operator-static-semantics-early-errors):
+            // `-2 ** 2` is an early SyntaxError, but `(-2) ** 2` is fine, since a
+            // parenthesized expression is parsed as a `UpdateExpression`, not a
+            // `UnaryExpression`, by the time it gets here.
+            if let Some(tok) = cursor.peek(0) {
+                if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind {
+                    return Err(ParseError::general(
+                        "unparenthesized unary expression can't appear on the left-hand side of '**'",
+                        tok.pos,
+                    ));
+                }
+            }
+
+            return Ok(unary);
         }
 
         let lhs =
             UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         if let Some(tok) = cursor.next() {
             if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind {
-                return Ok(Node::bin_op(
-                    BinOp::Num(NumOp::Exp),
-                    lhs,
-                    self.parse(cursor, interner)?,
-                ));
+                let rhs = self.parse(cursor, interner)?;
+                // `Node::bin_op` derives its span by merging `lhs`'s and
+                // `rhs`'s spans itself, so there's no separate span to
+                // compute here.
+                return Ok(Node::bin_op(BinOp::Num(NumOp::Exp), lhs, rhs));
             } else {
                 cursor.back();
             }
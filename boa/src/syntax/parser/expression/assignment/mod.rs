@@ -0,0 +1,49 @@
+//! Assignment expression parsing.
+//!
+//! This subset doesn't implement the conditional/assignment-operator chain
+//! above exponentiation, so `AssignmentExpression` delegates straight to
+//! `ExponentiationExpression`.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-AssignmentExpression
+
+mod exponentiation;
+
+use crate::{
+    syntax::parser::{
+        expression::assignment::exponentiation::ExponentiationExpression, AllowAwait, AllowIn,
+        AllowYield, Cursor, ParseResult, TokenParser,
+    },
+    Interner,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct AssignmentExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl AssignmentExpression {
+    /// Creates a new `AssignmentExpression` parser.
+    pub fn new<I, Y, A>(_allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for AssignmentExpression {
+    type Output = crate::syntax::ast::node::Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        ExponentiationExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner)
+    }
+}
@@ -0,0 +1,52 @@
+//! Expression parsing.
+
+pub mod assignment;
+mod primary;
+pub mod unary;
+pub mod update;
+
+pub use assignment::AssignmentExpression;
+
+use crate::{
+    syntax::{ast::punc::Punctuator, parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser}},
+    Interner,
+};
+
+/// Parses an `Initializer`: `= AssignmentExpression`.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Initializer
+#[derive(Debug, Clone, Copy)]
+pub struct Initializer {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl Initializer {
+    /// Creates a new `Initializer` parser.
+    pub fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for Initializer {
+    type Output = crate::syntax::ast::node::Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        cursor.expect(Punctuator::Assign, "initializer", interner)?;
+        AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)
+    }
+}
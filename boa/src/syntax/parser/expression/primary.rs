@@ -0,0 +1,69 @@
+//! Primary expression parsing: identifiers, literals, and parenthesized
+//! expressions.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-PrimaryExpression
+
+use crate::{
+    syntax::{
+        ast::{node::Node, punc::Punctuator, token::TokenKind},
+        parser::{
+            expression::assignment::AssignmentExpression, AllowAwait, AllowIn, AllowYield,
+            Cursor, ParseError, ParseResult, TokenParser,
+        },
+    },
+    Interner,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct PrimaryExpression {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PrimaryExpression {
+    pub(in crate::syntax::parser) fn new<I, Y, A>(
+        allow_in: I,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for PrimaryExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+        match tok.kind {
+            TokenKind::Identifier(name) => Ok(Node::identifier(name, tok.pos.into())),
+            TokenKind::StringLiteral(lit) => Ok(Node::string_literal(lit, tok.pos.into())),
+            TokenKind::NumericLiteral(n) => Ok(Node::numeric_literal(n, tok.pos.into())),
+            TokenKind::Punctuator(Punctuator::OpenParen) => {
+                let inner = AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                cursor.expect(Punctuator::CloseParen, "parenthesized expression", interner)?;
+                Ok(inner)
+            }
+            _ => Err(ParseError::expected(
+                vec![String::from("expression")],
+                tok.display(interner).to_string(),
+                tok.pos,
+                "primary expression",
+            )),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Unary expression parsing.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-UnaryExpression
+
+use crate::{
+    syntax::{
+        ast::{keyword::Keyword, node::Node, op::UnaryOp, punc::Punctuator, token::TokenKind},
+        parser::{
+            expression::update::UpdateExpression, AllowAwait, AllowYield, Cursor, ParseError,
+            ParseResult, TokenParser,
+        },
+    },
+    Interner,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::expression) struct UnaryExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl UnaryExpression {
+    pub(in crate::syntax::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for UnaryExpression {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        let Some(tok) = cursor.peek(0) else {
+            return Err(ParseError::AbruptEnd);
+        };
+
+        let op = match tok.kind {
+            TokenKind::Keyword(Keyword::Delete) => Some(UnaryOp::Delete),
+            TokenKind::Keyword(Keyword::Void) => Some(UnaryOp::Void),
+            TokenKind::Keyword(Keyword::TypeOf) => Some(UnaryOp::TypeOf),
+            TokenKind::Punctuator(Punctuator::Add) => Some(UnaryOp::Plus),
+            TokenKind::Punctuator(Punctuator::Sub) => Some(UnaryOp::Minus),
+            TokenKind::Punctuator(Punctuator::Not) => Some(UnaryOp::Not),
+            TokenKind::Punctuator(Punctuator::Neg) => Some(UnaryOp::Neg),
+            _ => None,
+        };
+
+        match op {
+            Some(op) => {
+                let start = tok.pos;
+                cursor.next();
+                let target = self.parse(cursor, interner)?;
+                let span = crate::syntax::ast::span::Span::new(start, target.span().end());
+                Ok(Node::unary_op(op, target, span))
+            }
+            None => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor, interner),
+        }
+    }
+}
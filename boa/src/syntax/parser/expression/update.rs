@@ -0,0 +1,44 @@
+//! Update expression parsing.
+//!
+//! This subset doesn't implement postfix `++`/`--`, so an `UpdateExpression`
+//! is just a `PrimaryExpression` for now.
+//!
+//! More information:
+//!  - [spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-UpdateExpression
+
+use crate::{
+    syntax::parser::{
+        expression::primary::PrimaryExpression, AllowAwait, AllowYield, Cursor, ParseResult,
+        TokenParser,
+    },
+    Interner,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::expression) struct UpdateExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl UpdateExpression {
+    pub(in crate::syntax::parser::expression) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for UpdateExpression {
+    type Output = crate::syntax::ast::node::Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        PrimaryExpression::new(true, self.allow_yield, self.allow_await).parse(cursor, interner)
+    }
+}
@@ -0,0 +1,103 @@
+//! Recursive-descent parser.
+
+mod cursor;
+mod error;
+
+pub mod expression;
+pub mod statement;
+
+pub use cursor::Cursor;
+pub use error::ParseError;
+
+use crate::{
+    syntax::{ast::node::Node, lexer},
+    Interner,
+};
+
+/// A parser of a single grammar production. Implementors are typically
+/// zero-sized (or small) configuration structs — see [`AllowIn`],
+/// [`AllowYield`], [`AllowAwait`] — built with `Self::new(...)` and consumed
+/// by `parse`.
+pub trait TokenParser: Sized {
+    type Output;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<'_>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError>;
+}
+
+/// The result of parsing a production whose output is a [`Node`].
+pub type ParseResult = Result<Node, ParseError>;
+
+macro_rules! allow_flag {
+    ($name:ident) => {
+        /// Whether the grammar parameter this flag is named after is in
+        /// scope for the production currently being parsed.
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        pub struct $name(bool);
+
+        impl From<bool> for $name {
+            fn from(b: bool) -> Self {
+                Self(b)
+            }
+        }
+
+        impl $name {
+            /// Returns the flag's value.
+            pub fn is_allowed(self) -> bool {
+                self.0
+            }
+        }
+    };
+}
+
+allow_flag!(AllowIn);
+allow_flag!(AllowYield);
+allow_flag!(AllowAwait);
+
+/// Parses full programs.
+pub struct Parser<'a> {
+    source: &'a [u8],
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new `Parser` over `source`.
+    pub fn new(source: &'a [u8]) -> Self {
+        Self { source }
+    }
+
+    fn lex(&self, interner: &mut Interner) -> Result<Vec<crate::syntax::ast::token::Token>, ParseError> {
+        let text = std::str::from_utf8(self.source).map_err(|_| lexer::LexError::InvalidUtf8)?;
+        Ok(lexer::tokenize(text, interner)?)
+    }
+
+    /// Parses the whole program, aborting at the first error.
+    pub fn parse_all(self) -> Result<Node, ParseError> {
+        let mut interner = Interner::new();
+        let tokens = self.lex(&mut interner)?;
+        let mut cursor = Cursor::new(tokens);
+        statement::StatementList::new(false, false).parse(&mut cursor, &mut interner)
+    }
+
+    /// Parses the whole program in error-recovery mode: instead of aborting
+    /// at the first error, parsers resynchronize at statement boundaries and
+    /// keep going, returning every diagnostic collected along the way.
+    pub fn parse_with_errors(self) -> (Node, Vec<ParseError>) {
+        let mut interner = Interner::new();
+        let tokens = match self.lex(&mut interner) {
+            Ok(tokens) => tokens,
+            Err(e) => return (Node::error(Default::default()), vec![e]),
+        };
+        let mut cursor = Cursor::new_recovering(tokens);
+        let node = match statement::StatementList::new(false, false).parse(&mut cursor, &mut interner) {
+            Ok(node) => node,
+            Err(e) => {
+                cursor.emit_error(e).expect("recovering cursor never re-raises");
+                Node::error(cursor.pos().into())
+            }
+        };
+        (node, cursor.take_errors())
+    }
+}
@@ -0,0 +1,111 @@
+//! Statement parsing.
+
+pub mod variable;
+
+use crate::{
+    syntax::{
+        ast::{keyword::Keyword, node::Node, node::NodeKind, span::Span, token::TokenKind},
+        parser::{
+            expression::AssignmentExpression,
+            statement::variable::{LexicalDeclaration, VariableStatement},
+            AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        },
+    },
+    Interner,
+};
+
+/// Parses a list of statements, up to end-of-file.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-StatementList
+#[derive(Debug, Clone, Copy)]
+pub struct StatementList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl StatementList {
+    /// Creates a new `StatementList` parser.
+    pub fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for StatementList {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        let start = cursor.peek(0).map_or_else(|| cursor.pos(), |tok| tok.pos);
+        let mut statements = Vec::new();
+        let mut strict = false;
+        // The directive prologue is the leading run of expression statements
+        // that are bare string literals; it ends at the first statement that
+        // isn't one of those (https://tc39.es/ecma262/#directive-prologue).
+        let mut in_prologue = true;
+
+        while let Some(tok) = cursor.peek(0) {
+            if tok.kind == TokenKind::EOF {
+                break;
+            }
+
+            let stmt = if tok.kind == TokenKind::Keyword(Keyword::Var) {
+                in_prologue = false;
+                VariableStatement::new(self.allow_yield, self.allow_await).parse(cursor, interner)?
+            } else if let TokenKind::Keyword(keyword @ (Keyword::Let | Keyword::Const)) = tok.kind {
+                in_prologue = false;
+                LexicalDeclaration::new(keyword, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?
+            } else {
+                // Parsed the same way as `VariableDeclarationList`/`VariableStatement`
+                // above: on failure, emit the error (fatal unless the cursor is
+                // recovering) and resynchronize instead of aborting the whole
+                // statement list, so one bad expression statement doesn't swallow
+                // every statement after it.
+                let parsed = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)
+                    .and_then(|expr| {
+                        cursor.expect_semicolon(false, "expression statement", interner)?;
+                        Ok(expr)
+                    });
+
+                match parsed {
+                    Ok(expr) => {
+                        if in_prologue {
+                            match expr.kind() {
+                                NodeKind::StringLiteral(lit)
+                                    if lit.is_use_strict_directive(interner) =>
+                                {
+                                    strict = true;
+                                }
+                                NodeKind::StringLiteral(_) => {}
+                                _ => in_prologue = false,
+                            }
+                        }
+
+                        expr
+                    }
+                    Err(e) => {
+                        cursor.emit_error(e)?;
+                        cursor.recover_to_sync_point();
+                        in_prologue = false;
+                        Node::error(cursor.pos().into())
+                    }
+                }
+            };
+
+            statements.push(stmt);
+        }
+
+        let end = cursor.peek(0).map_or(start, |tok| tok.pos);
+        Ok(Node::statement_list(statements, strict, Span::new(start, end)))
+    }
+}
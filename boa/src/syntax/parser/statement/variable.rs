@@ -1,13 +1,22 @@
-// use super::lexical_declaration_continuation;
 use crate::{
     syntax::{
-        ast::{keyword::Keyword, node::Node, punc::Punctuator, token::TokenKind},
+        ast::{
+            declaration::{
+                ArrayPatternElement, BindingPattern, BindingTarget, ObjectPatternElement,
+                PropertyKey,
+            },
+            keyword::Keyword,
+            node::Node,
+            punc::Punctuator,
+            span::Span,
+            token::TokenKind,
+        },
         parser::{
-            expression::Initializer, AllowAwait, AllowIn, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
+            expression::{AssignmentExpression, Initializer},
+            AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
-    Interner, Sym,
+    Interner,
 };
 
 /// Variable statement parsing.
@@ -49,7 +58,69 @@ impl TokenParser for VariableStatement {
         let decl_list = VariableDeclarationList::new(true, self.allow_yield, self.allow_await)
             .parse(cursor, interner)?;
 
-        cursor.expect_semicolon(false, "variable statement", interner)?;
+        if let Err(e) = cursor.expect_semicolon(false, "variable statement", interner) {
+            cursor.emit_error(e)?;
+            cursor.recover_to_sync_point();
+        }
+
+        Ok(decl_list)
+    }
+}
+
+/// Lexical (`let`/`const`) declaration parsing.
+///
+/// Shares `VariableDeclarationList`/`VariableDeclaration`/`Binding` with
+/// `VariableStatement` above — the only difference between `var
+/// VariableDeclarationList ;` and `let/const BindingList ;` is which keyword
+/// introduces it.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/let
+/// [spec]: https://tc39.es/ecma262/#prod-LexicalDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser::statement) struct LexicalDeclaration {
+    keyword: Keyword,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl LexicalDeclaration {
+    /// Creates a new `LexicalDeclaration` parser for `keyword` (`let` or
+    /// `const`).
+    pub(in crate::syntax::parser::statement) fn new<Y, A>(
+        keyword: Keyword,
+        allow_yield: Y,
+        allow_await: A,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        debug_assert!(matches!(keyword, Keyword::Let | Keyword::Const));
+        Self {
+            keyword,
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for LexicalDeclaration {
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        cursor.expect(self.keyword, "lexical declaration", interner)?;
+
+        let decl_list = VariableDeclarationList::new(true, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?;
+
+        if let Err(e) = cursor.expect_semicolon(false, "lexical declaration", interner) {
+            cursor.emit_error(e)?;
+            cursor.recover_to_sync_point();
+        }
 
         Ok(decl_list)
     }
@@ -94,13 +165,29 @@ impl TokenParser for VariableDeclarationList {
     type Output = Node;
 
     fn parse(self, cursor: &mut Cursor<'_>, interner: &mut Interner) -> ParseResult {
+        let start = cursor.peek(0).map_or_else(|| cursor.pos(), |tok| tok.pos);
         let mut list = Vec::new();
+        // Set once a declaration (or its separator) fails to parse. A
+        // malformed declaration list is never reported as a successful
+        // `VarDecl` of whatever happened to be collected before the error —
+        // callers need a placeholder they can tell apart from a real parse.
+        let mut failed = false;
 
         loop {
-            list.push(
-                VariableDeclaration::new(self.allow_in, self.allow_yield, self.allow_await)
-                    .parse(cursor, interner)?,
-            );
+            match VariableDeclaration::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)
+            {
+                Ok(decl) => list.push(decl),
+                // `emit_error` re-raises the error immediately unless the cursor is
+                // running in recovery mode, in which case it records the diagnostic
+                // and lets us resynchronize instead of aborting the whole parse.
+                Err(e) => {
+                    cursor.emit_error(e)?;
+                    cursor.recover_to_sync_point();
+                    failed = true;
+                    break;
+                }
+            }
 
             match cursor.peek_semicolon(false) {
                 (true, _) => break,
@@ -108,7 +195,7 @@ impl TokenParser for VariableDeclarationList {
                     let _ = cursor.next();
                 }
                 (false, Some(tk)) => {
-                    return Err(ParseError::expected(
+                    let e = ParseError::expected(
                         vec![
                             Punctuator::Semicolon.to_string(),
                             Punctuator::Comma.to_string(),
@@ -116,13 +203,23 @@ impl TokenParser for VariableDeclarationList {
                         tk.display(interner).to_string(),
                         tk.pos,
                         "lexical declaration",
-                    ))
+                    );
+                    cursor.emit_error(e)?;
+                    cursor.recover_to_sync_point();
+                    failed = true;
+                    break;
                 }
                 _ => unreachable!(),
             }
         }
 
-        Ok(Node::VarDecl(list))
+        let end = cursor.peek(0).map_or(start, |tok| tok.pos);
+        let span = Span::new(start, end);
+        Ok(if failed {
+            Node::error(span)
+        } else {
+            Node::var_decl(list, span)
+        })
     }
 }
 
@@ -156,34 +253,412 @@ impl VariableDeclaration {
 }
 
 impl TokenParser for VariableDeclaration {
-    type Output = (Sym, Option<Node>);
+    type Output = (BindingTarget, Option<Node>);
 
     fn parse(
         self,
         cursor: &mut Cursor<'_>,
         interner: &mut Interner,
     ) -> Result<Self::Output, ParseError> {
-        let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
-        let name = if let TokenKind::Identifier(name) = tok.kind {
-            name.clone()
-        } else {
-            return Err(ParseError::expected(
-                vec![String::from("identifier")],
-                tok.display(interner).to_string(),
-                tok.pos,
-                "variable declaration",
-            ));
-        };
+        let target = Binding::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?;
+
+        match cursor.peek(0) {
+            Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Assign) => Ok((
+                target,
+                Some(
+                    Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                ),
+            )),
+            // A destructuring pattern with no initializer has nothing to bind
+            // its names to (https://tc39.es/ecma262/#sec-destructuring-binding-patterns-static-semantics-early-errors):
+            // `var {a};` and `let [a];` are early SyntaxErrors, unlike a plain
+            // `BindingIdentifier`, which may be declared without one.
+            _ if matches!(target, BindingTarget::Pattern(_)) => Err(ParseError::general(
+                "missing initializer in destructuring declaration",
+                cursor.pos(),
+            )),
+            _ => Ok((target, None)),
+        }
+    }
+}
+
+/// Parses a `BindingIdentifier` or a destructuring `BindingPattern`.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Binding
+#[derive(Debug, Clone, Copy)]
+struct Binding {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl Binding {
+    /// Creates a new `Binding` parser.
+    fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for Binding {
+    type Output = BindingTarget;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<'_>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        match cursor.peek(0) {
+            Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                Ok(BindingTarget::Pattern(BindingPattern::Object(
+                    ObjectBindingPattern::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                )))
+            }
+            Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                Ok(BindingTarget::Pattern(BindingPattern::Array(
+                    ArrayBindingPattern::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                )))
+            }
+            Some(_) => {
+                let tok = cursor.next().expect("token disappeared");
+                if let TokenKind::Identifier(name) = tok.kind {
+                    Ok(BindingTarget::Identifier(name))
+                } else {
+                    Err(ParseError::expected(
+                        vec![
+                            String::from("identifier"),
+                            Punctuator::OpenBlock.to_string(),
+                            Punctuator::OpenBracket.to_string(),
+                        ],
+                        tok.display(interner).to_string(),
+                        tok.pos,
+                        "binding",
+                    ))
+                }
+            }
+            None => Err(ParseError::AbruptEnd),
+        }
+    }
+}
+
+/// Parses an object binding pattern: `{ a, b: c, d = 1, ...rest }`.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl TokenParser for ObjectBindingPattern {
+    type Output = Vec<ObjectPatternElement>;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<'_>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern", interner)?;
+
+        let mut elements = Vec::new();
+
+        loop {
+            match cursor.peek(0) {
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                    let _ = cursor.next();
+                    break;
+                }
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Spread) => {
+                    let _ = cursor.next();
+                    let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+                    let ident = if let TokenKind::Identifier(name) = tok.kind {
+                        name
+                    } else {
+                        return Err(ParseError::expected(
+                            vec![String::from("identifier")],
+                            tok.display(interner).to_string(),
+                            tok.pos,
+                            "object binding pattern rest property",
+                        ));
+                    };
+                    elements.push(ObjectPatternElement::RestProperty(ident));
+                    cursor.expect(
+                        Punctuator::CloseBlock,
+                        "object binding pattern rest property",
+                        interner,
+                    )?;
+                    break;
+                }
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let _ = cursor.next();
+                    let key_expr =
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor, interner)?;
+                    cursor.expect(
+                        Punctuator::CloseBracket,
+                        "computed object binding pattern key",
+                        interner,
+                    )?;
+                    cursor.expect(
+                        Punctuator::Colon,
+                        "object binding pattern property",
+                        interner,
+                    )?;
+                    let (target, default_init) =
+                        self.parse_binding_with_default(cursor, interner)?;
+                    elements.push(ObjectPatternElement::Property {
+                        key: PropertyKey::Computed(key_expr),
+                        target,
+                        default_init,
+                    });
+
+                    match cursor.next() {
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Comma) => {}
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                            break
+                        }
+                        Some(tk) => {
+                            return Err(ParseError::expected(
+                                vec![
+                                    Punctuator::Comma.to_string(),
+                                    Punctuator::CloseBlock.to_string(),
+                                ],
+                                tk.display(interner).to_string(),
+                                tk.pos,
+                                "object binding pattern",
+                            ))
+                        }
+                        None => return Err(ParseError::AbruptEnd),
+                    }
+                }
+                Some(_) => {
+                    let tok = cursor.next().ok_or(ParseError::AbruptEnd)?;
+                    let name = if let TokenKind::Identifier(name) = tok.kind {
+                        name
+                    } else {
+                        return Err(ParseError::expected(
+                            vec![String::from("identifier")],
+                            tok.display(interner).to_string(),
+                            tok.pos,
+                            "object binding pattern",
+                        ));
+                    };
+
+                    match cursor.peek(0) {
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Colon) => {
+                            let _ = cursor.next();
+                            let (target, default_init) =
+                                self.parse_binding_with_default(cursor, interner)?;
+                            elements.push(ObjectPatternElement::Property {
+                                key: PropertyKey::Literal(name),
+                                target,
+                                default_init,
+                            });
+                        }
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Assign) => {
+                            let default_init = Some(
+                                Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                                    .parse(cursor, interner)?,
+                            );
+                            elements.push(ObjectPatternElement::SingleName {
+                                ident: name,
+                                default_init,
+                            });
+                        }
+                        _ => elements.push(ObjectPatternElement::SingleName {
+                            ident: name,
+                            default_init: None,
+                        }),
+                    }
+
+                    match cursor.next() {
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Comma) => {}
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                            break
+                        }
+                        Some(tk) => {
+                            return Err(ParseError::expected(
+                                vec![
+                                    Punctuator::Comma.to_string(),
+                                    Punctuator::CloseBlock.to_string(),
+                                ],
+                                tk.display(interner).to_string(),
+                                tk.pos,
+                                "object binding pattern",
+                            ))
+                        }
+                        None => return Err(ParseError::AbruptEnd),
+                    }
+                }
+                None => return Err(ParseError::AbruptEnd),
+            }
+        }
+
+        Ok(elements)
+    }
+}
+
+impl ObjectBindingPattern {
+    /// Parses the `BindingElement` target following a `PropertyName :`, along
+    /// with its optional `Initializer`.
+    fn parse_binding_with_default(
+        self,
+        cursor: &mut Cursor<'_>,
+        interner: &mut Interner,
+    ) -> Result<(BindingTarget, Option<Node>), ParseError> {
+        let target = Binding::new(self.allow_in, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?;
 
         match cursor.peek(0) {
             Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Assign) => Ok((
-                name,
+                target,
                 Some(
                     Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?,
                 ),
             )),
-            _ => Ok((name, None)),
+            _ => Ok((target, None)),
+        }
+    }
+}
+
+/// Parses an array binding pattern: `[x, , y = 2, ...rest]`.
+///
+/// More information:
+///  - [spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_in: AllowIn,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<I, Y, A>(allow_in: I, allow_yield: Y, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
         }
     }
 }
+
+impl TokenParser for ArrayBindingPattern {
+    type Output = Vec<Option<ArrayPatternElement>>;
+
+    fn parse(
+        self,
+        cursor: &mut Cursor<'_>,
+        interner: &mut Interner,
+    ) -> Result<Self::Output, ParseError> {
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern", interner)?;
+
+        let mut elements = Vec::new();
+
+        loop {
+            match cursor.peek(0) {
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::CloseBracket) => {
+                    let _ = cursor.next();
+                    break;
+                }
+                // An elision: an empty slot that binds nothing.
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Comma) => {
+                    let _ = cursor.next();
+                    elements.push(None);
+                }
+                Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Spread) => {
+                    let _ = cursor.next();
+                    let target = Binding::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    elements.push(Some(ArrayPatternElement::Rest(target)));
+                    cursor.expect(
+                        Punctuator::CloseBracket,
+                        "array binding pattern rest element",
+                        interner,
+                    )?;
+                    break;
+                }
+                Some(_) => {
+                    let target = Binding::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?;
+                    let default_init = match cursor.peek(0) {
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Assign) => Some(
+                            Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                                .parse(cursor, interner)?,
+                        ),
+                        _ => None,
+                    };
+                    elements.push(Some(ArrayPatternElement::SingleName {
+                        target,
+                        default_init,
+                    }));
+
+                    match cursor.next() {
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::Comma) => {}
+                        Some(tk) if tk.kind == TokenKind::Punctuator(Punctuator::CloseBracket) => {
+                            break
+                        }
+                        Some(tk) => {
+                            return Err(ParseError::expected(
+                                vec![
+                                    Punctuator::Comma.to_string(),
+                                    Punctuator::CloseBracket.to_string(),
+                                ],
+                                tk.display(interner).to_string(),
+                                tk.pos,
+                                "array binding pattern",
+                            ))
+                        }
+                        None => return Err(ParseError::AbruptEnd),
+                    }
+                }
+                None => return Err(ParseError::AbruptEnd),
+            }
+        }
+
+        Ok(elements)
+    }
+}
@@ -0,0 +1,42 @@
+//! `var`/`let`/`const` declaration coverage, including destructuring.
+
+use boa::syntax::parser::Parser;
+
+#[test]
+fn let_declaration_parses() {
+    assert!(Parser::new(b"let a = 1;").parse_all().is_ok());
+}
+
+#[test]
+fn const_declaration_parses() {
+    assert!(Parser::new(b"const a = 1;").parse_all().is_ok());
+}
+
+#[test]
+fn var_with_object_destructuring_and_initializer_parses() {
+    assert!(Parser::new(b"var { a, b } = c;").parse_all().is_ok());
+}
+
+#[test]
+fn let_with_array_destructuring_and_initializer_parses() {
+    assert!(Parser::new(b"let [a, b] = c;").parse_all().is_ok());
+}
+
+#[test]
+fn object_destructuring_without_initializer_is_rejected() {
+    // https://tc39.es/ecma262/#sec-destructuring-binding-patterns-static-semantics-early-errors
+    assert!(Parser::new(b"var { a };").parse_all().is_err());
+    assert!(Parser::new(b"let { a };").parse_all().is_err());
+}
+
+#[test]
+fn array_destructuring_without_initializer_is_rejected() {
+    assert!(Parser::new(b"var [a];").parse_all().is_err());
+    assert!(Parser::new(b"const [a];").parse_all().is_err());
+}
+
+#[test]
+fn plain_identifier_without_initializer_is_accepted() {
+    assert!(Parser::new(b"var a;").parse_all().is_ok());
+    assert!(Parser::new(b"let a;").parse_all().is_ok());
+}
@@ -0,0 +1,42 @@
+//! `Parser::parse_with_errors` error-recovery coverage.
+
+use boa::syntax::parser::Parser;
+
+#[test]
+fn parse_all_aborts_at_the_first_error() {
+    let err = Parser::new(b"var {a};\nvar b;\n")
+        .parse_all()
+        .expect_err("destructuring declaration with no initializer must be rejected");
+    assert!(!err.to_string().is_empty());
+}
+
+#[test]
+fn parse_with_errors_recovers_past_a_bad_variable_declaration() {
+    let (_, errors) = Parser::new(b"var {a};\nvar b;\n").parse_with_errors();
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn parse_with_errors_recovers_past_a_bad_expression_statement() {
+    // `-2 ** 2` is an early error (unparenthesized unary base of `**`); without
+    // recovering in the non-`var` branch of `StatementList::parse` the same
+    // way `VariableStatement` does, this single bad expression statement used
+    // to abort the entire remaining document instead of just itself.
+    let (_, errors) = Parser::new(b"var {a};\n-2 ** 2;\nvar {b};\n").parse_with_errors();
+    assert_eq!(
+        errors.len(),
+        3,
+        "expected all three malformed statements to be reported, got {errors:?}"
+    );
+}
+
+#[test]
+fn non_utf8_source_is_a_parse_error_not_a_panic() {
+    // `Parser::new` takes `&[u8]`, which invites arbitrary (e.g. file) input;
+    // lexing it used to `.expect()` valid UTF-8 and panic instead of
+    // returning an error.
+    let err = Parser::new(b"var a = \xff;")
+        .parse_all()
+        .expect_err("non-UTF-8 source must be rejected, not panic");
+    assert!(!err.to_string().is_empty());
+}
@@ -0,0 +1,19 @@
+//! Early-error coverage for the `**` exponentiation operator.
+
+use boa::syntax::parser::Parser;
+
+#[test]
+fn unparenthesized_unary_base_is_rejected() {
+    // https://tc39.es/ecma262/#sec-exp-operator-static-semantics-early-errors
+    assert!(Parser::new(b"-2 ** 2;").parse_all().is_err());
+}
+
+#[test]
+fn parenthesized_unary_base_is_accepted() {
+    assert!(Parser::new(b"(-2) ** 2;").parse_all().is_ok());
+}
+
+#[test]
+fn non_unary_base_is_accepted() {
+    assert!(Parser::new(b"2 ** 2;").parse_all().is_ok());
+}
@@ -0,0 +1,64 @@
+//! `Span` / `Node::eq_ignoring_span` coverage.
+
+use boa::{
+    assert_eq_ignore_span,
+    syntax::{
+        ast::{
+            node::{Node, NodeKind},
+            span::Span,
+        },
+        parser::Parser,
+    },
+};
+
+fn parse_first_statement(source: &str) -> Node {
+    let node = Parser::new(source.as_bytes())
+        .parse_all()
+        .unwrap_or_else(|e| panic!("expected {source:?} to parse: {e}"));
+    match node.kind() {
+        NodeKind::StatementList { statements, .. } => statements[0].clone(),
+        other => panic!("expected a StatementList, got {other:?}"),
+    }
+}
+
+#[test]
+fn bin_op_span_merges_operand_spans() {
+    let bin_op = parse_first_statement("1 ** 2;");
+    match bin_op.kind() {
+        NodeKind::BinOp { lhs, rhs, .. } => {
+            assert_eq!(
+                bin_op.span(),
+                Span::new(lhs.span().start(), rhs.span().end())
+            );
+        }
+        other => panic!("expected a BinOp, got {other:?}"),
+    }
+}
+
+#[test]
+fn eq_ignoring_span_ignores_nested_spans_too() {
+    // Extra spacing around the operator shifts `rhs`'s span, not just the
+    // top-level statement's — `eq_ignoring_span` must ignore both.
+    let a = parse_first_statement("1 ** 2;");
+    let b = parse_first_statement("1   **   2;");
+    assert_ne!(
+        a.span(),
+        b.span(),
+        "precondition: differing whitespace must produce differing spans"
+    );
+    assert_eq_ignore_span!(a, b);
+}
+
+#[test]
+fn eq_ignoring_span_still_distinguishes_different_asts() {
+    let a = parse_first_statement("1 ** 2;");
+    let b = parse_first_statement("1 ** 3;");
+    assert!(!a.eq_ignoring_span(&b));
+}
+
+#[test]
+fn plain_eq_is_span_sensitive() {
+    let a = parse_first_statement("1 ** 2;");
+    let b = parse_first_statement("1   **   2;");
+    assert_ne!(a, b, "Node's PartialEq must compare spans");
+}
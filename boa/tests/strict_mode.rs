@@ -0,0 +1,31 @@
+//! Directive prologue / `"use strict"` detection.
+
+use boa::syntax::{ast::node::NodeKind, parser::Parser};
+
+fn parse_strict(source: &str) -> bool {
+    let node = Parser::new(source.as_bytes())
+        .parse_all()
+        .unwrap_or_else(|e| panic!("expected {source:?} to parse: {e}"));
+    match node.kind() {
+        NodeKind::StatementList { strict, .. } => *strict,
+        other => panic!("expected a StatementList, got {other:?}"),
+    }
+}
+
+#[test]
+fn unescaped_use_strict_enables_strict_mode() {
+    assert!(parse_strict("\"use strict\"; var a;"));
+    assert!(parse_strict("'use strict'; var a;"));
+}
+
+#[test]
+fn escaped_use_strict_does_not_enable_strict_mode() {
+    // Cooks to the same string as `"use strict"`, but the escaped space
+    // means it isn't a valid directive: https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive
+    assert!(!parse_strict("\"use\\ strict\"; var a;"));
+}
+
+#[test]
+fn use_strict_after_other_statements_does_not_enable_strict_mode() {
+    assert!(!parse_strict("var a; \"use strict\";"));
+}